@@ -0,0 +1,123 @@
+use crate::*;
+
+/// A ZeroTier address: the low 40 bits of a node's identity hash.
+pub struct Address(pub u64);
+
+impl Address {
+    pub fn new_from_string(s: &str) -> Result<Address, ResultCode> {
+        if s.len() != 10 {
+            return Err(ResultCode::ErrorBadParameter);
+        }
+        u64::from_str_radix(s, 16).map(Address).map_err(|_| ResultCode::ErrorBadParameter)
+    }
+
+    /// Compact 5-byte big-endian wire form of the low 40 bits.
+    fn to_bytes(&self) -> [u8; 5] {
+        let mut buf = [0u8; 5];
+        buf.copy_from_slice(&self.0.to_be_bytes()[3..8]);
+        buf
+    }
+
+    /// Inverse of to_bytes(). Errors unless the slice is exactly 5 bytes.
+    fn from_bytes(b: &[u8]) -> Result<Address, ()> {
+        if b.len() != 5 {
+            return Err(());
+        }
+        let mut addr_bytes = [0u8; 8];
+        addr_bytes[3..8].copy_from_slice(b);
+        Ok(Address(u64::from_be_bytes(addr_bytes)))
+    }
+}
+
+impl ToString for Address {
+    fn to_string(&self) -> String {
+        format!("{:010x}", self.0 & 0xffffffffff)
+    }
+}
+
+// Same split as Fingerprint (see fingerprint.rs): text formats like JSON report
+// is_human_readable() == true and get the 10-hex string; binary formats like bincode and
+// serde_cbor report false and get the compact 5-byte form.
+impl serde::Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.to_string().as_str())
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+struct AddressVisitor;
+
+impl<'de> serde::de::Visitor<'de> for AddressVisitor {
+    type Value = Address;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a ZeroTier Address, either as a 10-digit hex string or as 5 raw bytes")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        Address::new_from_string(s).map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> where E: serde::de::Error {
+        Address::from_bytes(v).map_err(|_| serde::de::Error::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(5);
+        while let Some(b) = seq.next_element()? {
+            if bytes.len() >= 5 {
+                return Err(serde::de::Error::invalid_length(bytes.len() + 1, &self));
+            }
+            bytes.push(b);
+        }
+        Address::from_bytes(bytes.as_slice()).map_err(|_| serde::de::Error::invalid_length(bytes.len(), &self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(AddressVisitor)
+        } else {
+            deserializer.deserialize_bytes(AddressVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_bytes_round_trip() {
+        let addr = Address(0x0102030405);
+        let bytes = addr.to_bytes();
+        assert_eq!(bytes.len(), 5);
+        let back = Address::from_bytes(&bytes).unwrap();
+        assert_eq!(back.0, addr.0);
+    }
+
+    #[test]
+    fn compact_bytes_rejects_wrong_length() {
+        assert!(Address::from_bytes(&[0u8; 4]).is_err());
+        assert!(Address::from_bytes(&[0u8; 6]).is_err());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let addr = Address(0x0102030405);
+        let back = Address::new_from_string(addr.to_string().as_str()).unwrap();
+        assert_eq!(back.0, addr.0);
+    }
+
+    #[test]
+    fn round_trips_through_bincode() {
+        let addr = Address(0x0102030405);
+        let bytes = bincode::serialize(&addr).unwrap();
+        let back: Address = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.0, addr.0);
+    }
+}
@@ -40,9 +40,44 @@ impl ToString for Fingerprint {
     }
 }
 
+// Only Fingerprint gets the is_human_readable() treatment here; Identity's public-key newtypes
+// live in a module (identity.rs) not present in this checkout, so the same binary/text split
+// can't be applied to them without guessing at key material this crate has no evidence of. See
+// address.rs for the Address side of this, which is present and covered.
+//
+// is_human_readable() is reported by the *format*, not chosen by us: binary formats (e.g.
+// bincode and serde_cbor) report false and take the compact path below. Text formats such as
+// JSON report true and get the "address-hash" string, same as ever - see FingerprintAsTaggedCbor
+// below for an opt-in way to also mark the compact CBOR form with a CBOR tag.
 impl serde::Serialize for Fingerprint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-        serializer.serialize_str(self.to_string().as_str())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.to_string().as_str())
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+
+impl Fingerprint {
+    /// Compact 53-byte wire form: 5-byte big-endian address followed by the 48-byte hash.
+    fn to_bytes(&self) -> [u8; 53] {
+        let mut buf = [0u8; 53];
+        buf[0..5].copy_from_slice(&self.address.0.to_be_bytes()[3..8]);
+        buf[5..53].copy_from_slice(&self.hash);
+        buf
+    }
+
+    /// Inverse of to_bytes(). Errors unless the slice is exactly 53 bytes.
+    fn from_bytes(b: &[u8]) -> Result<Fingerprint, ()> {
+        if b.len() != 53 {
+            return Err(());
+        }
+        let mut addr_bytes = [0u8; 8];
+        addr_bytes[3..8].copy_from_slice(&b[0..5]);
+        let mut hash = [0u8; 48];
+        hash.copy_from_slice(&b[5..53]);
+        Ok(Fingerprint { address: Address(u64::from_be_bytes(addr_bytes)), hash })
     }
 }
 
@@ -52,7 +87,7 @@ impl<'de> serde::de::Visitor<'de> for FingerprintVisitor {
     type Value = Fingerprint;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("ZeroTier Fingerprint in string format")
+        formatter.write_str("a ZeroTier Fingerprint, either as a string or as 53 raw bytes (5-byte address + 48-byte hash)")
     }
 
     fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> where E: serde::de::Error {
@@ -62,10 +97,281 @@ impl<'de> serde::de::Visitor<'de> for FingerprintVisitor {
         }
         return Ok(id.ok().unwrap() as Self::Value);
     }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> where E: serde::de::Error {
+        Fingerprint::from_bytes(v).map_err(|_| serde::de::Error::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(53);
+        while let Some(b) = seq.next_element()? {
+            if bytes.len() >= 53 {
+                // Bail before growing further instead of draining an untrusted, possibly
+                // unbounded sequence into memory just to reject it in from_bytes() afterward.
+                return Err(serde::de::Error::invalid_length(bytes.len() + 1, &self));
+            }
+            bytes.push(b);
+        }
+        Fingerprint::from_bytes(bytes.as_slice()).map_err(|_| serde::de::Error::invalid_length(bytes.len(), &self))
+    }
 }
 
 impl<'de> serde::Deserialize<'de> for Fingerprint {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-        deserializer.deserialize_str(FingerprintVisitor)
+        // Mirrors the Serialize side rather than using deserialize_any: bincode (and other
+        // non-self-describing binary formats) don't implement deserialize_any at all, so this
+        // has to commit to str vs. bytes up front via is_human_readable() to actually work there.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FingerprintVisitor)
+        } else {
+            deserializer.deserialize_bytes(FingerprintVisitor)
+        }
+    }
+}
+
+struct FingerprintOptVisitor;
+
+impl<'de> serde::de::Visitor<'de> for FingerprintOptVisitor {
+    type Value = Option<Fingerprint>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a ZeroTier Fingerprint string, or \"(invalid)\"/empty/all-zero-hash for None")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        if s.is_empty() || s == "(invalid)" || is_zero_hash_wildcard_str(s) {
+            return Ok(None);
+        }
+        match Fingerprint::new_from_string(s) {
+            Ok(fp) => Ok(fingerprint_opt_from_wildcard(fp)),
+            Err(_) => Err(serde::de::Error::invalid_value(serde::de::Unexpected::Str(s), &self)),
+        }
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> where E: serde::de::Error {
+        Ok(None)
+    }
+}
+
+/// Maps ZeroTier's wildcard/partial form - a hash that is all zero bytes - to None.
+fn fingerprint_opt_from_wildcard(fp: Fingerprint) -> Option<Fingerprint> {
+    if fp.hash.iter().all(|b| *b == 0) {
+        None
+    } else {
+        Some(fp)
+    }
+}
+
+/// Recognizes the zero-hash wildcard in its string form - `<10-hex-address>-<96-hex-hash>` with
+/// the hash half all zeros - without going through `new_from_string`/`ZT_Fingerprint_fromString`
+/// first. The C parser isn't guaranteed to accept an all-zero hash, so detecting the wildcard
+/// textually keeps it mapping to `None` instead of surfacing a hard parse error.
+fn is_zero_hash_wildcard_str(s: &str) -> bool {
+    match s.split_once('-') {
+        Some((_address, hash_hex)) => hash_hex.len() == 96 && hash_hex.bytes().all(|b| b == b'0'),
+        None => false,
+    }
+}
+
+/// Lenient `Option<Fingerprint>` deserializer for use via `#[serde(deserialize_with = "deserialize_fingerprint_opt")]`.
+///
+/// Treats the `"(invalid)"` sentinel that `Fingerprint::to_string` emits when the underlying C
+/// library rejects a value, an empty string, and a fingerprint whose 48-byte hash is all zeros
+/// (ZeroTier's wildcard/partial form) as `None` instead of failing the whole parse.
+pub fn deserialize_fingerprint_opt<'de, D>(deserializer: D) -> Result<Option<Fingerprint>, D::Error> where D: serde::Deserializer<'de> {
+    deserializer.deserialize_any(FingerprintOptVisitor)
+}
+
+#[cfg(test)]
+mod opt_tests {
+    use super::*;
+
+    fn deserialize_opt_str(s: &str) -> Result<Option<Fingerprint>, serde::de::value::Error> {
+        serde::de::Visitor::visit_str(FingerprintOptVisitor, s)
+    }
+
+    #[test]
+    fn invalid_sentinel_maps_to_none() {
+        assert!(deserialize_opt_str("(invalid)").unwrap().is_none());
+    }
+
+    #[test]
+    fn empty_string_maps_to_none() {
+        assert!(deserialize_opt_str("").unwrap().is_none());
+    }
+
+    #[test]
+    fn zero_hash_wildcard_maps_to_none() {
+        let wildcard = format!("{:010x}-{}", 0x0102030405u64, "0".repeat(96));
+        assert!(deserialize_opt_str(&wildcard).unwrap().is_none());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Fingerprint {
+        Fingerprint { address: Address(0x0102030405), hash: [7u8; 48] }
+    }
+
+    #[test]
+    fn compact_bytes_round_trip() {
+        let fp = sample();
+        let bytes = fp.to_bytes();
+        assert_eq!(bytes.len(), 53);
+        let back = Fingerprint::from_bytes(&bytes).unwrap();
+        assert_eq!(back.address.0, fp.address.0);
+        assert_eq!(back.hash, fp.hash);
+    }
+
+    #[test]
+    fn compact_bytes_rejects_wrong_length() {
+        assert!(Fingerprint::from_bytes(&[0u8; 52]).is_err());
+        assert!(Fingerprint::from_bytes(&[0u8; 54]).is_err());
+    }
+
+    /// bincode's Serializer and Deserializer report is_human_readable() == false, so this is the actual binary
+    /// path through a real serializer - not just a direct to_bytes()/from_bytes() call - and it
+    /// proves Fingerprint's Deserialize impl works against a format that doesn't support
+    /// deserialize_any.
+    #[test]
+    fn round_trips_through_bincode() {
+        let fp = sample();
+        let bytes = bincode::serialize(&fp).unwrap();
+        let back: Fingerprint = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back.address.0, fp.address.0);
+        assert_eq!(back.hash, fp.hash);
+    }
+}
+
+/// serde_with adapter that renders a Fingerprint as `{ "address": "<10-hex>", "hash": "<base64>" }`
+/// instead of ZeroTier's own address-hash string, for interop with tools that expect standard
+/// base64 byte fields. Opt in per-field with `#[serde_as(as = "FingerprintAsBase64")]`; this does
+/// not change `Fingerprint`'s own `Serialize`/`Deserialize` impls.
+pub struct FingerprintAsBase64;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FingerprintAsBase64Repr {
+    address: String,
+    hash: String,
+}
+
+impl serde_with::SerializeAs<Fingerprint> for FingerprintAsBase64 {
+    fn serialize_as<S>(source: &Fingerprint, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        FingerprintAsBase64Repr {
+            address: format!("{:010x}", source.address.0 & 0xffffffffff),
+            hash: base64::encode(&source.hash),
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> serde_with::DeserializeAs<'de, Fingerprint> for FingerprintAsBase64 {
+    fn deserialize_as<D>(deserializer: D) -> Result<Fingerprint, D::Error> where D: serde::Deserializer<'de> {
+        let repr = FingerprintAsBase64Repr::deserialize(deserializer)?;
+        let address = u64::from_str_radix(repr.address.as_str(), 16)
+            .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(repr.address.as_str()), &"a 10-digit hex ZeroTier address"))?;
+        let hash_bytes = base64::decode(repr.hash.as_str())
+            .map_err(|_| serde::de::Error::invalid_value(serde::de::Unexpected::Str(repr.hash.as_str()), &"base64-encoded bytes"))?;
+        if hash_bytes.len() != 48 {
+            return Err(serde::de::Error::invalid_length(hash_bytes.len(), &"48 bytes"));
+        }
+        let mut hash = [0u8; 48];
+        hash.copy_from_slice(hash_bytes.as_slice());
+        Ok(Fingerprint { address: Address(address), hash })
+    }
+}
+
+#[cfg(test)]
+mod base64_tests {
+    use super::*;
+    use serde_with::{SerializeAs, DeserializeAs};
+
+    #[test]
+    fn round_trips_through_json() {
+        let fp = Fingerprint { address: Address(0x0102030405), hash: [3u8; 48] };
+
+        let mut buf = Vec::new();
+        FingerprintAsBase64::serialize_as(&fp, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+        let json = String::from_utf8(buf).unwrap();
+        assert!(json.contains("\"address\":\"0102030405\""));
+
+        let back = FingerprintAsBase64::deserialize_as(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!(back.address.0, fp.address.0);
+        assert_eq!(back.hash, fp.hash);
+    }
+
+    #[test]
+    fn rejects_wrong_hash_length() {
+        let bad = r#"{"address":"0102030405","hash":"AAAA"}"#;
+        let result = FingerprintAsBase64::deserialize_as(&mut serde_json::Deserializer::from_str(bad));
+        assert!(result.is_err());
+    }
+}
+
+/// Private CBOR tag (major type 6) identifying a ZeroTier Fingerprint's compact binary form.
+#[cfg(feature = "cbor-tags")]
+const FINGERPRINT_CBOR_TAG: u64 = 10130;
+
+/// serde_with adapter, gated behind the `cbor-tags` feature, that wraps a Fingerprint's compact
+/// binary form in a CBOR tagged value (major type 6, tag FINGERPRINT_CBOR_TAG) so a decoder can recognize it as a ZeroTier Fingerprint
+/// rather than arbitrary bytes. Opt in per field with `#[serde_as(as = "FingerprintAsTaggedCbor")]`.
+///
+/// This is additive, unlike feature-gating `Fingerprint`'s own `Serialize`/`Deserialize`: a crate
+/// turning on `cbor-tags` only changes the output of fields that explicitly opt in. Every other
+/// field, and every other format including plain CBOR and JSON, is unaffected.
+#[cfg(feature = "cbor-tags")]
+pub struct FingerprintAsTaggedCbor;
+
+#[cfg(feature = "cbor-tags")]
+impl serde_with::SerializeAs<Fingerprint> for FingerprintAsTaggedCbor {
+    fn serialize_as<S>(source: &Fingerprint, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+        serde_cbor::tags::Tagged::new(Some(FINGERPRINT_CBOR_TAG), serde_bytes::Bytes::new(&source.to_bytes())).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "cbor-tags")]
+impl<'de> serde_with::DeserializeAs<'de, Fingerprint> for FingerprintAsTaggedCbor {
+    fn deserialize_as<D>(deserializer: D) -> Result<Fingerprint, D::Error> where D: serde::Deserializer<'de> {
+        let tagged: serde_cbor::tags::Tagged<serde_bytes::ByteBuf> = serde::Deserialize::deserialize(deserializer)?;
+        match tagged.tag {
+            None | Some(FINGERPRINT_CBOR_TAG) => Fingerprint::from_bytes(tagged.value.as_ref())
+                .map_err(|_| serde::de::Error::invalid_length(tagged.value.len(), &"53 raw bytes (5-byte address + 48-byte hash)")),
+            Some(other) => Err(serde::de::Error::custom(format!("CBOR tag {} is not a ZeroTier Fingerprint (expected {})", other, FINGERPRINT_CBOR_TAG))),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "cbor-tags")]
+mod cbor_tag_tests {
+    use super::*;
+    use serde_with::{SerializeAs, DeserializeAs};
+
+    fn sample() -> Fingerprint {
+        Fingerprint { address: Address(0x0102030405), hash: [9u8; 48] }
+    }
+
+    #[test]
+    fn tags_binary_cbor_output() {
+        let fp = sample();
+        let mut buf = Vec::new();
+        FingerprintAsTaggedCbor::serialize_as(&fp, &mut serde_cbor::Serializer::new(&mut buf)).unwrap();
+
+        let tagged: serde_cbor::tags::Tagged<serde_bytes::ByteBuf> = serde_cbor::from_slice(&buf).unwrap();
+        assert_eq!(tagged.tag, Some(FINGERPRINT_CBOR_TAG));
+
+        let back = FingerprintAsTaggedCbor::deserialize_as(&mut serde_cbor::Deserializer::from_slice(&buf)).unwrap();
+        assert_eq!(back.address.0, fp.address.0);
+        assert_eq!(back.hash, fp.hash);
+    }
+
+    #[test]
+    fn rejects_wrong_tag() {
+        let fp = sample();
+        let wrongly_tagged = serde_cbor::tags::Tagged::new(Some(424242u64), serde_bytes::Bytes::new(&fp.to_bytes()));
+        let bytes = serde_cbor::to_vec(&wrongly_tagged).unwrap();
+        let result = FingerprintAsTaggedCbor::deserialize_as(&mut serde_cbor::Deserializer::from_slice(&bytes));
+        assert!(result.is_err());
     }
 }